@@ -0,0 +1,47 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A user-defined language cleaning profile, e.g. for Zig's `zig-cache` or
+/// Elixir's `_build`, declared in a `[[profile]]` table in the config file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub indicators: Vec<String>,
+    pub targets: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(rename = "profile", default)]
+    profiles: Vec<Profile>,
+}
+
+/// Load custom profiles from `config_path` if given, otherwise from the
+/// discovered `~/.config/begone/config.toml`. Returns an empty list if no
+/// path is given and the default file does not exist; a given path that
+/// cannot be read or parsed is an error.
+pub fn load_profiles(config_path: Option<&Path>) -> Result<Vec<Profile>> {
+    let path = match config_path {
+        Some(path) => Some(path.to_path_buf()),
+        None => default_config_path().filter(|path| path.exists()),
+    };
+
+    let Some(path) = path else {
+        return Ok(Vec::new());
+    };
+
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read config file {}", path.display()))?;
+    let config: ConfigFile = toml::from_str(&contents)
+        .with_context(|| format!("failed to parse config file {}", path.display()))?;
+
+    Ok(config.profiles)
+}
+
+fn default_config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("begone").join("config.toml"))
+}
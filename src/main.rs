@@ -1,13 +1,63 @@
-use anyhow::Result;
-use clap::{Parser, Subcommand};
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
 use colored::*;
+use flate2::{write::GzEncoder, Compression};
 use log::{debug, error, info, warn};
+use rayon::prelude::*;
+use serde::Serialize;
 use std::{
     fs,
-    path::Path,
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Mutex,
+    },
+    time::{Duration, SystemTime},
 };
 use walkdir::WalkDir;
 
+mod config;
+
+/// Archive format used to snapshot a project directory before cleaning it
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum ArchiveFormat {
+    /// gzip-compressed tarball (.tar.gz)
+    TarGz,
+    /// zip archive (.zip)
+    Zip,
+    /// zstd-compressed tarball (.tar.zst)
+    Zstd,
+}
+
+impl ArchiveFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::Zip => "zip",
+            ArchiveFormat::Zstd => "tar.zst",
+        }
+    }
+}
+
+/// How results are rendered: colored human-readable lines, a single
+/// machine-readable JSON report, or newline-delimited JSON for streaming
+/// consumers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+    Ndjson,
+}
+
+impl OutputFormat {
+    /// Whether this format replaces the colored per-candidate log lines with a
+    /// structured report.
+    fn is_structured(self) -> bool {
+        self != OutputFormat::Text
+    }
+}
+
 #[derive(Parser)]
 #[command(name = "begone")]
 #[command(author, version, about, long_about = None)]
@@ -20,6 +70,26 @@ struct Cli {
     /// Enable verbose output
     #[arg(short, long, default_value_t = false)]
     verbose: bool,
+    /// Archive the whole project directory before cleaning its build artifacts
+    #[arg(long, value_enum)]
+    archive: Option<ArchiveFormat>,
+    /// Skip projects whose files were modified within the last N days
+    #[arg(long, value_name = "DAYS")]
+    min_age: Option<u64>,
+    /// Path to a TOML config file declaring custom language profiles
+    /// (defaults to the discovered ~/.config/begone/config.toml)
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+    /// Number of directories to scan and remove concurrently (default: number of CPUs)
+    #[arg(short = 'j', long, value_name = "N")]
+    jobs: Option<usize>,
+    /// Interactively pick which target directories to clean before removing them
+    #[arg(short, long, default_value_t = false)]
+    interactive: bool,
+    /// Output format: colored text for humans, a single JSON report, or
+    /// newline-delimited JSON, for scripting
+    #[arg(long, value_enum)]
+    format: Option<OutputFormat>,
 }
 
 #[derive(Subcommand)]
@@ -38,6 +108,14 @@ enum Commands {
     Dotnet,
     /// Clean all supported project directories
     All,
+    /// Scan the tree once and clean every supported project type found
+    /// (faster than `all` on large trees, which walks once per language)
+    Auto,
+    /// Clean a custom profile declared in the config file
+    Clean {
+        /// Name of the profile to clean, matching a `[[profile]]` entry's `name`
+        profile: String,
+    },
 }
 
 fn main() -> Result<()> {
@@ -55,178 +133,1215 @@ fn main() -> Result<()> {
     let current_dir = std::env::current_dir()?;
     debug!("Current directory: {}", current_dir.display());
 
-    match &cli.command {
-        Commands::Rust => clean_rust(&current_dir, cli.dry_run)?,
-        Commands::Python => clean_python(&current_dir, cli.dry_run)?,
-        Commands::Js => clean_js(&current_dir, cli.dry_run)?,
-        Commands::Java => clean_java(&current_dir, cli.dry_run)?,
-        Commands::Go => clean_go(&current_dir, cli.dry_run)?,
-        Commands::Dotnet => clean_dotnet(&current_dir, cli.dry_run)?,
+    let jobs = cli.jobs.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+    debug!("Using {} worker(s)", jobs);
+
+    let profiles = config::load_profiles(cli.config.as_deref())?;
+    let format = cli.format.unwrap_or(OutputFormat::Text);
+
+    if cli.interactive && format.is_structured() {
+        anyhow::bail!(
+            "--interactive cannot be combined with --format json/ndjson; \
+             structured output is meant for non-interactive/CI use"
+        );
+    }
+
+    let results = match &cli.command {
+        Commands::Rust => clean_rust(&current_dir, cli.dry_run, cli.archive, cli.min_age, cli.interactive, format, jobs)?,
+        Commands::Python => clean_python(&current_dir, cli.dry_run, cli.archive, cli.min_age, cli.interactive, format, jobs)?,
+        Commands::Js => clean_js(&current_dir, cli.dry_run, cli.archive, cli.min_age, cli.interactive, format, jobs)?,
+        Commands::Java => clean_java(&current_dir, cli.dry_run, cli.archive, cli.min_age, cli.interactive, format, jobs)?,
+        Commands::Go => clean_go(&current_dir, cli.dry_run, cli.archive, cli.min_age, cli.interactive, format, jobs)?,
+        Commands::Dotnet => clean_dotnet(&current_dir, cli.dry_run, cli.archive, cli.min_age, cli.interactive, format, jobs)?,
         Commands::All => {
-            clean_rust(&current_dir, cli.dry_run)?;
-            clean_python(&current_dir, cli.dry_run)?;
-            clean_js(&current_dir, cli.dry_run)?;
-            clean_java(&current_dir, cli.dry_run)?;
-            clean_go(&current_dir, cli.dry_run)?;
-            clean_dotnet(&current_dir, cli.dry_run)?;
+            info!("Cleaning all supported projects in: {}", current_dir.display());
+
+            // Discover every language's candidates before touching `process_candidates`,
+            // so interactive mode shows one consolidated checklist instead of one prompt
+            // per language (each `clean_*` call used to select and remove independently).
+            let mut candidates = Vec::new();
+            candidates.extend(discover_candidates(&current_dir, &["Cargo.toml"], &["target"], "Rust", cli.min_age, jobs)?);
+            candidates.extend(discover_candidates(
+                &current_dir,
+                &["requirements.txt", "pyproject.toml", "setup.py", "Pipfile"],
+                &[".venv", "venv", "__pycache__", ".pytest_cache", ".mypy_cache"],
+                "Python",
+                cli.min_age,
+                jobs,
+            )?);
+            candidates.extend(discover_candidates(
+                &current_dir,
+                &["package.json"],
+                &["node_modules", ".next", ".nuxt", ".cache", "dist", "build"],
+                "JavaScript/TypeScript",
+                cli.min_age,
+                jobs,
+            )?);
+            candidates.extend(discover_candidates(
+                &current_dir,
+                &["pom.xml", "build.gradle", "build.gradle.kts"],
+                &["target", "build", ".gradle", ".classpath"],
+                "Java",
+                cli.min_age,
+                jobs,
+            )?);
+            candidates.extend(discover_candidates(&current_dir, &["go.mod", "go.sum"], &["bin", "pkg", "__debug_bin"], "Go", cli.min_age, jobs)?);
+            candidates.extend(discover_candidates(
+                &current_dir,
+                &["*.csproj", "*.fsproj", "*.sln"],
+                &["bin", "obj"],
+                ".NET",
+                cli.min_age,
+                jobs,
+            )?);
+            for profile in &profiles {
+                let indicators: Vec<&str> = profile.indicators.iter().map(String::as_str).collect();
+                let targets: Vec<&str> = profile.targets.iter().map(String::as_str).collect();
+                candidates.extend(discover_candidates(&current_dir, &indicators, &targets, &profile.name, cli.min_age, jobs)?);
+            }
+
+            let all = process_candidates(candidates, cli.archive, cli.dry_run, cli.interactive, format, jobs)?;
+
+            let (total_cleaned, _, total_bytes) = summarize(&all);
+            if total_cleaned > 0 {
+                info!(
+                    "{} {} project{} across all languages, freed {}",
+                    if cli.dry_run { "Would remove" } else { "Removed" },
+                    total_cleaned,
+                    if total_cleaned != 1 { "s" } else { "" },
+                    format_bytes(total_bytes),
+                );
+            } else {
+                info!("No projects found to clean");
+            }
+            all
         }
+        Commands::Auto => {
+            let languages = merged_languages(&profiles);
+            auto_clean(&current_dir, &languages, cli.dry_run, cli.archive, cli.min_age, cli.interactive, format, jobs)?
+        }
+        Commands::Clean { profile } => {
+            let profile = profiles
+                .iter()
+                .find(|p| &p.name == profile)
+                .with_context(|| {
+                    format!(
+                        "no profile named '{}' found in config (have: {})",
+                        profile,
+                        profiles
+                            .iter()
+                            .map(|p| p.name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })?;
+            let indicators: Vec<&str> = profile.indicators.iter().map(String::as_str).collect();
+            let targets: Vec<&str> = profile.targets.iter().map(String::as_str).collect();
+            clean_directories(
+                &current_dir,
+                &indicators,
+                &targets,
+                &profile.name,
+                cli.dry_run,
+                cli.archive,
+                cli.min_age,
+                cli.interactive,
+                format,
+                jobs,
+            )?
+        }
+    };
+
+    match format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => print_json_report(&results, cli.dry_run)?,
+        OutputFormat::Ndjson => print_ndjson_report(&results, cli.dry_run)?,
     }
 
     Ok(())
 }
 
-fn clean_rust(dir: &Path, dry_run: bool) -> Result<()> {
+fn clean_rust(
+    dir: &Path,
+    dry_run: bool,
+    archive: Option<ArchiveFormat>,
+    min_age: Option<u64>,
+    interactive: bool,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<Vec<CandidateResult>> {
     clean_directories(
         dir,
         &["Cargo.toml"],
         &["target"],
         "Rust",
         dry_run,
+        archive,
+        min_age,
+        interactive,
+        format,
+        jobs,
     )
 }
 
-fn clean_python(dir: &Path, dry_run: bool) -> Result<()> {
+fn clean_python(
+    dir: &Path,
+    dry_run: bool,
+    archive: Option<ArchiveFormat>,
+    min_age: Option<u64>,
+    interactive: bool,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<Vec<CandidateResult>> {
     clean_directories(
         dir,
         &["requirements.txt", "pyproject.toml", "setup.py", "Pipfile"],
         &[".venv", "venv", "__pycache__", ".pytest_cache", ".mypy_cache"],
         "Python",
         dry_run,
+        archive,
+        min_age,
+        interactive,
+        format,
+        jobs,
     )
 }
 
-fn clean_js(dir: &Path, dry_run: bool) -> Result<()> {
+fn clean_js(
+    dir: &Path,
+    dry_run: bool,
+    archive: Option<ArchiveFormat>,
+    min_age: Option<u64>,
+    interactive: bool,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<Vec<CandidateResult>> {
     clean_directories(
         dir,
         &["package.json"],
         &["node_modules", ".next", ".nuxt", ".cache", "dist", "build"],
         "JavaScript/TypeScript",
         dry_run,
+        archive,
+        min_age,
+        interactive,
+        format,
+        jobs,
     )
 }
 
-fn clean_java(dir: &Path, dry_run: bool) -> Result<()> {
+fn clean_java(
+    dir: &Path,
+    dry_run: bool,
+    archive: Option<ArchiveFormat>,
+    min_age: Option<u64>,
+    interactive: bool,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<Vec<CandidateResult>> {
     clean_directories(
         dir,
         &["pom.xml", "build.gradle", "build.gradle.kts"],
         &["target", "build", ".gradle", ".classpath"],
         "Java",
         dry_run,
+        archive,
+        min_age,
+        interactive,
+        format,
+        jobs,
     )
 }
 
-fn clean_go(dir: &Path, dry_run: bool) -> Result<()> {
+fn clean_go(
+    dir: &Path,
+    dry_run: bool,
+    archive: Option<ArchiveFormat>,
+    min_age: Option<u64>,
+    interactive: bool,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<Vec<CandidateResult>> {
     clean_directories(
         dir,
         &["go.mod", "go.sum"],
         &["bin", "pkg", "__debug_bin"],
         "Go",
         dry_run,
+        archive,
+        min_age,
+        interactive,
+        format,
+        jobs,
     )
 }
 
-fn clean_dotnet(dir: &Path, dry_run: bool) -> Result<()> {
+fn clean_dotnet(
+    dir: &Path,
+    dry_run: bool,
+    archive: Option<ArchiveFormat>,
+    min_age: Option<u64>,
+    interactive: bool,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<Vec<CandidateResult>> {
     clean_directories(
         dir,
         &["*.csproj", "*.fsproj", "*.sln"],
         &["bin", "obj"],
         ".NET",
         dry_run,
+        archive,
+        min_age,
+        interactive,
+        format,
+        jobs,
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn clean_directories(
     root_dir: &Path,
     indicator_files: &[&str],
     target_dirs: &[&str],
     language: &str,
     dry_run: bool,
-) -> Result<()> {
+    archive: Option<ArchiveFormat>,
+    min_age: Option<u64>,
+    interactive: bool,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<Vec<CandidateResult>> {
     info!("Cleaning {} projects in: {}", language, root_dir.display());
-    let mut cleaned = 0;
-    let mut skipped = 0;
 
-    // Walk through all directories
-    for entry in WalkDir::new(root_dir)
-        .min_depth(0) 
+    let candidates = discover_candidates(root_dir, indicator_files, target_dirs, language, min_age, jobs)?;
+    let results = process_candidates(candidates, archive, dry_run, interactive, format, jobs)?;
+    let (cleaned, _, freed_bytes) = summarize(&results);
+
+    if cleaned > 0 {
+        info!(
+            "{} {} {}{}, freed {}",
+            if dry_run { "Would remove" } else { "Removed" },
+            cleaned,
+            language,
+            if cleaned != 1 { " projects" } else { " project" },
+            format_bytes(freed_bytes),
+        );
+    } else {
+        info!("No {} projects found to clean", language);
+    }
+
+    Ok(results)
+}
+
+/// A target directory discovered during a scan, pending an archive/removal decision.
+#[derive(Clone)]
+struct Candidate {
+    /// Project root containing the indicator file; archived as a whole when requested.
+    project_dir: PathBuf,
+    /// The build artifact directory to remove, e.g. `<project_dir>/target`.
+    target_path: PathBuf,
+    language: String,
+    size_bytes: u64,
+}
+
+/// Walk `root_dir` and collect every target dir belonging to a matching, non-stale
+/// project. This is the discovery phase; nothing is archived or removed here.
+///
+/// Enumerating the tree itself is a cheap, inherently sequential directory walk, but
+/// testing each directory for indicator files and sizing its target dir is real I/O,
+/// so that part runs across `jobs` threads.
+fn discover_candidates(
+    root_dir: &Path,
+    indicator_files: &[&str],
+    target_dirs: &[&str],
+    language: &str,
+    min_age: Option<u64>,
+    jobs: usize,
+) -> Result<Vec<Candidate>> {
+    let dirs: Vec<PathBuf> = WalkDir::new(root_dir)
+        .min_depth(0)
         .into_iter()
         .filter_map(Result::ok)
         .filter(|e| e.file_type().is_dir())
-    {
-        let dir_path = entry.path();
-        
-        // Check if this directory contains any of the indicator files
-        let has_indicator = indicator_files.iter().any(|pattern| {
-            if pattern.starts_with('*') {
-                // Handle wildcard patterns like "*.csproj"
-                let pattern = &pattern[1..]; // Remove the leading '*'
-                dir_path
-                    .read_dir()
-                    .map(|mut entries| {
-                        entries.any(|e| {
-                            e.ok()
-                                .and_then(|e| e.file_name().to_str().map(|s| s.ends_with(pattern)))
-                                .unwrap_or(false)
+        .map(|e| e.into_path())
+        .collect();
+
+    let pool = build_thread_pool(jobs)?;
+    Ok(pool.install(|| {
+        dirs.par_iter()
+            .flat_map(|dir_path| {
+                if !has_indicator_file(dir_path, indicator_files) {
+                    return Vec::new();
+                }
+
+                if let Some(min_age_days) = min_age {
+                    if !is_project_stale(dir_path, target_dirs, min_age_days) {
+                        info!("Skipping {} (recently active)", dir_path.display());
+                        return Vec::new();
+                    }
+                }
+
+                target_dirs
+                    .iter()
+                    .filter_map(|target_dir| {
+                        let target_path = dir_path.join(target_dir);
+                        target_path.exists().then(|| Candidate {
+                            project_dir: dir_path.clone(),
+                            size_bytes: dir_size(&target_path),
+                            target_path,
+                            language: language.to_string(),
                         })
                     })
-                    .unwrap_or(false)
-            } else {
-                dir_path.join(pattern).exists()
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }))
+}
+
+/// Drop candidates whose `target_path` duplicates or is nested inside another
+/// candidate's `target_path`, keeping the shallowest (outermost) one.
+///
+/// Two situations produce overlapping candidates: `auto`/`all` running several
+/// languages that share a folder name (e.g. Rust's `Cargo.toml` and Java's
+/// `pom.xml` both pointing at `target`), and a single language whose indicator files
+/// recur inside their own target dir (every `node_modules/<dep>` carries a
+/// `package.json`, so `node_modules` and `node_modules/<dep>/node_modules` both
+/// become candidates). Parallel removal can't tolerate either: two workers
+/// racing to remove the same path, or one removing a directory out from under
+/// another that's mid-removal, both surface as nondeterministic "Failed to
+/// remove" errors. Removing the outer candidate always takes care of the
+/// nested one, so we only ever need to keep the shallowest path.
+fn dedup_candidates(candidates: Vec<Candidate>) -> Vec<Candidate> {
+    let mut candidates = candidates;
+    candidates.sort_by_key(|c| c.target_path.components().count());
+
+    let mut kept: Vec<PathBuf> = Vec::new();
+    candidates
+        .into_iter()
+        .filter(|c| {
+            let overlaps = kept.iter().any(|k| c.target_path.starts_with(k));
+            if !overlaps {
+                kept.push(c.target_path.clone());
             }
-        });
+            !overlaps
+        })
+        .collect()
+}
+
+/// If `interactive`, let the user pick which candidates to keep via a checklist
+/// prompt; otherwise pass all of them through untouched.
+fn select_candidates(candidates: Vec<Candidate>, interactive: bool) -> Result<Vec<Candidate>> {
+    if !interactive || candidates.is_empty() {
+        return Ok(candidates);
+    }
+
+    let items: Vec<String> = candidates
+        .iter()
+        .map(|c| {
+            format!(
+                "{} {} ({})",
+                c.target_path.display(),
+                format_bytes(c.size_bytes),
+                c.language
+            )
+        })
+        .collect();
+    let defaults = vec![true; items.len()];
+
+    let selected = dialoguer::MultiSelect::new()
+        .with_prompt("Select target directories to clean (space to toggle, enter to confirm)")
+        .items(&items)
+        .defaults(&defaults)
+        .interact()
+        .context("interactive selection failed")?;
+
+    Ok(selected.into_iter().map(|i| candidates[i].clone()).collect())
+}
+
+/// Archive each distinct project directory represented in `candidates` once, then
+/// remove their target dirs in parallel. Returns one `CandidateResult` per candidate.
+fn process_candidates(
+    candidates: Vec<Candidate>,
+    archive: Option<ArchiveFormat>,
+    dry_run: bool,
+    interactive: bool,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<Vec<CandidateResult>> {
+    let quiet = format.is_structured();
+    let candidates = dedup_candidates(candidates);
+    let candidates = select_candidates(candidates, interactive)?;
+    if candidates.is_empty() {
+        return Ok(Vec::new());
+    }
 
-        if has_indicator {
-            for target_dir in target_dirs {
-                let target_path = dir_path.join(target_dir);
-                if target_path.exists() {
-                    if dry_run {
+    // Projects whose archive step failed: their target dirs must not be removed,
+    // otherwise a failed snapshot still loses the build artifacts it was meant to
+    // preserve a restorable copy of.
+    let mut failed_archives = std::collections::HashSet::new();
+    if let Some(archive_format) = archive {
+        let mut archived_dirs = std::collections::HashSet::new();
+        for candidate in &candidates {
+            if archived_dirs.insert(candidate.project_dir.clone()) {
+                match archive_project(&candidate.project_dir, archive_format, dry_run, quiet) {
+                    Ok(true) => debug!("Archived {}", candidate.project_dir.display()),
+                    Ok(false) => debug!(
+                        "Archive for {} is up to date, skipping",
+                        candidate.project_dir.display()
+                    ),
+                    Err(e) => {
+                        error!("Failed to archive {}: {}", candidate.project_dir.display(), e);
+                        failed_archives.insert(candidate.project_dir.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    let pool = build_thread_pool(jobs)?;
+    let skipped = AtomicU32::new(0);
+    let output = Mutex::new(());
+
+    let results = pool.install(|| {
+        candidates
+            .par_iter()
+            .map(|candidate| {
+                if failed_archives.contains(&candidate.project_dir) {
+                    warn!(
+                        "Skipping removal of {} (archive of {} failed)",
+                        candidate.target_path.display(),
+                        candidate.project_dir.display()
+                    );
+                    skipped.fetch_add(1, Ordering::Relaxed);
+                    return CandidateResult {
+                        path: candidate.target_path.clone(),
+                        language: candidate.language.clone(),
+                        size_bytes: candidate.size_bytes,
+                        action: ActionKind::Failed,
+                        error: Some("archive failed, skipped removal".to_string()),
+                    };
+                }
+
+                if dry_run {
+                    if !quiet {
+                        let _guard = output.lock().unwrap();
                         println!(
                             "{} {} {}",
                             "Would remove:".yellow().bold(),
-                            target_path.display(),
-                            format!("({} project)", language).dimmed()
+                            candidate.target_path.display(),
+                            format!("({}, {} project)", format_bytes(candidate.size_bytes), candidate.language)
+                                .dimmed()
                         );
-                        cleaned += 1;
-                    } else {
-                        match fs::remove_dir_all(&target_path) {
-                            Ok(_) => {
-                                println!(
-                                    "{} {}",
-                                    "Removed:".green().bold(),
-                                    target_path.display()
-                                );
-                                cleaned += 1;
-                            }
-                            Err(e) => {
-                                error!(
-                                    "Failed to remove {}: {}",
-                                    target_path.display(),
-                                    e
-                                );
-                                skipped += 1;
-                            }
+                    }
+                    return CandidateResult {
+                        path: candidate.target_path.clone(),
+                        language: candidate.language.clone(),
+                        size_bytes: candidate.size_bytes,
+                        action: ActionKind::WouldRemove,
+                        error: None,
+                    };
+                }
+
+                match fs::remove_dir_all(&candidate.target_path) {
+                    Ok(_) => {
+                        if !quiet {
+                            let _guard = output.lock().unwrap();
+                            println!(
+                                "{} {} {}",
+                                "Removed:".green().bold(),
+                                candidate.target_path.display(),
+                                format!("({})", format_bytes(candidate.size_bytes)).dimmed()
+                            );
+                        }
+                        CandidateResult {
+                            path: candidate.target_path.clone(),
+                            language: candidate.language.clone(),
+                            size_bytes: candidate.size_bytes,
+                            action: ActionKind::Removed,
+                            error: None,
+                        }
+                    }
+                    Err(e) => {
+                        error!("Failed to remove {}: {}", candidate.target_path.display(), e);
+                        skipped.fetch_add(1, Ordering::Relaxed);
+                        CandidateResult {
+                            path: candidate.target_path.clone(),
+                            language: candidate.language.clone(),
+                            size_bytes: candidate.size_bytes,
+                            action: ActionKind::Failed,
+                            error: Some(e.to_string()),
                         }
                     }
                 }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let skipped = skipped.into_inner();
+    if skipped > 0 {
+        warn!("Did not remove {} directories (archive failed, permission denied or in use)", skipped);
+    }
+
+    Ok(results)
+}
+
+/// The outcome of attempting to remove a single candidate's target directory.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ActionKind {
+    Removed,
+    WouldRemove,
+    Failed,
+}
+
+/// A single candidate's outcome, shared by the human-readable log lines and the
+/// `--format json` report.
+#[derive(Clone, Debug, Serialize)]
+struct CandidateResult {
+    path: PathBuf,
+    language: String,
+    size_bytes: u64,
+    action: ActionKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Summary {
+    action: String,
+    count: u32,
+    bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    results: Vec<CandidateResult>,
+    summary: Summary,
+}
+
+/// Tally how many results were removed (or would be) vs. failed, and how many
+/// bytes that freed.
+fn summarize(results: &[CandidateResult]) -> (u32, u32, u64) {
+    let mut cleaned = 0;
+    let mut skipped = 0;
+    let mut freed_bytes = 0;
+
+    for result in results {
+        match result.action {
+            ActionKind::Removed | ActionKind::WouldRemove => {
+                cleaned += 1;
+                freed_bytes += result.size_bytes;
             }
+            ActionKind::Failed => skipped += 1,
         }
     }
 
-    if cleaned > 0 || skipped > 0 {
-        let action = if dry_run { "Would remove" } else { "Removed" };
+    (cleaned, skipped, freed_bytes)
+}
+
+/// Print the full set of results as a single JSON report on stdout.
+fn print_json_report(results: &[CandidateResult], dry_run: bool) -> Result<()> {
+    let (count, _, bytes) = summarize(results);
+    let report = Report {
+        results: results.to_vec(),
+        summary: Summary {
+            action: if dry_run { "would_remove" } else { "removed" }.to_string(),
+            count,
+            bytes,
+        },
+    };
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+/// Print one compact JSON object per result, followed by a final summary object,
+/// as newline-delimited JSON for consumers that stream the output rather than
+/// parsing a single top-level document.
+fn print_ndjson_report(results: &[CandidateResult], dry_run: bool) -> Result<()> {
+    for result in results {
+        println!("{}", serde_json::to_string(result)?);
+    }
+
+    let (count, _, bytes) = summarize(results);
+    let summary = Summary {
+        action: if dry_run { "would_remove" } else { "removed" }.to_string(),
+        count,
+        bytes,
+    };
+    println!("{}", serde_json::to_string(&summary)?);
+    Ok(())
+}
+
+/// Criteria used to detect whether a directory is a project root for a given
+/// language. `auto` tests every matcher against each directory of one tree walk;
+/// each matcher checks its own file names, and a matcher with extension suffixes
+/// lists the directory with its own `read_dir`, so listings are not shared
+/// between matchers.
+///
+/// Built from the built-in languages as well as any `[[profile]]` entries in the
+/// config file, so a custom profile participates in `auto` the same as a
+/// built-in one.
+struct LanguageMatcher {
+    language: String,
+    /// Exact indicator file names, e.g. `Cargo.toml`.
+    files: Vec<String>,
+    /// File extension suffixes, including the leading dot, e.g. `.csproj`.
+    extensions: Vec<String>,
+    /// Build artifact directories to remove once matched.
+    folders: Vec<String>,
+}
+
+impl LanguageMatcher {
+    fn matches(&self, dir_path: &Path) -> bool {
+        let has_file = self.files.iter().any(|f| dir_path.join(f).exists());
+        if has_file {
+            return true;
+        }
+
+        if self.extensions.is_empty() {
+            return false;
+        }
+
+        dir_path
+            .read_dir()
+            .map(|entries| {
+                entries.filter_map(Result::ok).any(|e| {
+                    e.file_name()
+                        .to_str()
+                        .map(|name| self.extensions.iter().any(|ext| name.ends_with(ext.as_str())))
+                        .unwrap_or(false)
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    /// Build a matcher from a user-defined profile, splitting its indicators into
+    /// exact file names and `*`-prefixed extension patterns the same way
+    /// `has_indicator_file` does for the `clean` subcommand.
+    fn from_profile(profile: &config::Profile) -> Self {
+        let mut files = Vec::new();
+        let mut extensions = Vec::new();
+        for indicator in &profile.indicators {
+            match indicator.strip_prefix('*') {
+                Some(suffix) => extensions.push(suffix.to_string()),
+                None => files.push(indicator.clone()),
+            }
+        }
+
+        LanguageMatcher {
+            language: profile.name.clone(),
+            files,
+            extensions,
+            folders: profile.targets.clone(),
+        }
+    }
+}
+
+fn builtin_languages() -> Vec<LanguageMatcher> {
+    vec![
+        LanguageMatcher {
+            language: "Rust".to_string(),
+            files: vec!["Cargo.toml".to_string()],
+            extensions: vec![],
+            folders: vec!["target".to_string()],
+        },
+        LanguageMatcher {
+            language: "Python".to_string(),
+            files: ["requirements.txt", "pyproject.toml", "setup.py", "Pipfile"]
+                .map(String::from)
+                .to_vec(),
+            extensions: vec![],
+            folders: [".venv", "venv", "__pycache__", ".pytest_cache", ".mypy_cache"]
+                .map(String::from)
+                .to_vec(),
+        },
+        LanguageMatcher {
+            language: "JavaScript/TypeScript".to_string(),
+            files: vec!["package.json".to_string()],
+            extensions: vec![],
+            folders: ["node_modules", ".next", ".nuxt", ".cache", "dist", "build"]
+                .map(String::from)
+                .to_vec(),
+        },
+        LanguageMatcher {
+            language: "Java".to_string(),
+            files: ["pom.xml", "build.gradle", "build.gradle.kts"]
+                .map(String::from)
+                .to_vec(),
+            extensions: vec![],
+            folders: ["target", "build", ".gradle", ".classpath"]
+                .map(String::from)
+                .to_vec(),
+        },
+        LanguageMatcher {
+            language: "Go".to_string(),
+            files: ["go.mod", "go.sum"].map(String::from).to_vec(),
+            extensions: vec![],
+            folders: ["bin", "pkg", "__debug_bin"].map(String::from).to_vec(),
+        },
+        LanguageMatcher {
+            language: ".NET".to_string(),
+            files: vec![],
+            extensions: [".csproj", ".fsproj", ".sln"].map(String::from).to_vec(),
+            folders: ["bin", "obj"].map(String::from).to_vec(),
+        },
+    ]
+}
+
+/// Built-in languages plus every profile declared in the config file, so `auto`
+/// sees custom profiles the same way `clean <profile>` does.
+fn merged_languages(profiles: &[config::Profile]) -> Vec<LanguageMatcher> {
+    let mut languages = builtin_languages();
+    languages.extend(profiles.iter().map(LanguageMatcher::from_profile));
+    languages
+}
+
+/// Single-pass equivalent of running every built-in `clean_*` function: walks the
+/// tree once and, for each directory, tests it against every registered language
+/// instead of walking the whole tree once per language.
+#[allow(clippy::too_many_arguments)]
+fn auto_clean(
+    root_dir: &Path,
+    languages: &[LanguageMatcher],
+    dry_run: bool,
+    archive: Option<ArchiveFormat>,
+    min_age: Option<u64>,
+    interactive: bool,
+    format: OutputFormat,
+    jobs: usize,
+) -> Result<Vec<CandidateResult>> {
+    info!("Scanning for all supported projects in: {}", root_dir.display());
+
+    let dirs: Vec<PathBuf> = WalkDir::new(root_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_dir())
+        .map(|e| e.into_path())
+        .collect();
+
+    let pool = build_thread_pool(jobs)?;
+    let candidates: Vec<Candidate> = pool.install(|| {
+        dirs.par_iter()
+            .flat_map(|dir_path| {
+                languages
+                    .iter()
+                    .filter(|matcher| matcher.matches(dir_path))
+                    .flat_map(|matcher| {
+                        let folders: Vec<&str> = matcher.folders.iter().map(String::as_str).collect();
+                        if let Some(min_age_days) = min_age {
+                            if !is_project_stale(dir_path, &folders, min_age_days) {
+                                info!(
+                                    "Skipping {} ({}, recently active)",
+                                    dir_path.display(),
+                                    matcher.language
+                                );
+                                return Vec::new();
+                            }
+                        }
+
+                        matcher
+                            .folders
+                            .iter()
+                            .filter_map(|target_dir| {
+                                let target_path = dir_path.join(target_dir);
+                                target_path.exists().then(|| Candidate {
+                                    project_dir: dir_path.clone(),
+                                    size_bytes: dir_size(&target_path),
+                                    target_path,
+                                    language: matcher.language.to_string(),
+                                })
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    });
+
+    let results = process_candidates(candidates, archive, dry_run, interactive, format, jobs)?;
+    let (cleaned, _, freed_bytes) = summarize(&results);
+
+    if cleaned > 0 {
         info!(
-            "{} {} {}{}",
-            action,
+            "{} {} project{}, freed {}",
+            if dry_run { "Would remove" } else { "Removed" },
             cleaned,
-            language,
-            if cleaned != 1 { " projects" } else { " project" },
+            if cleaned != 1 { "s" } else { "" },
+            format_bytes(freed_bytes),
         );
-        if skipped > 0 {
-            warn!("Failed to remove {} directories (permission denied or in use)", skipped);
+    } else {
+        info!("No projects found to clean");
+    }
+
+    Ok(results)
+}
+
+/// Check whether `dir_path` contains any of `indicator_files`, which may include
+/// wildcard patterns like `*.csproj`.
+fn has_indicator_file(dir_path: &Path, indicator_files: &[&str]) -> bool {
+    indicator_files.iter().any(|pattern| {
+        if let Some(suffix) = pattern.strip_prefix('*') {
+            dir_path
+                .read_dir()
+                .map(|entries| {
+                    entries.filter_map(Result::ok).any(|e| {
+                        e.file_name()
+                            .to_str()
+                            .map(|s| s.ends_with(suffix))
+                            .unwrap_or(false)
+                    })
+                })
+                .unwrap_or(false)
+        } else {
+            dir_path.join(pattern).exists()
         }
+    })
+}
+
+/// Build a bounded thread pool used to archive/remove target directories concurrently.
+fn build_thread_pool(jobs: usize) -> Result<rayon::ThreadPool> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .context("failed to build thread pool")
+}
+
+/// Recursively sum the byte size of every file under `path`.
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Format a byte count as a human-readable binary size, e.g. "3.4 GiB".
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
     } else {
-        info!("No {} projects found to clean", language);
+        format!("{:.1} {}", size, UNITS[unit])
     }
+}
 
-    Ok(())
+/// Compress `project_dir` into an archive alongside it, unless an up-to-date
+/// archive already exists. Returns whether an archive was (or would be) written.
+fn archive_project(
+    project_dir: &Path,
+    format: ArchiveFormat,
+    dry_run: bool,
+    quiet: bool,
+) -> Result<bool> {
+    let project_name = project_dir
+        .file_name()
+        .context("project directory has no name")?
+        .to_string_lossy()
+        .into_owned();
+    let archive_path = project_dir
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(format!("{}.{}", project_name, format.extension()));
+
+    if is_archive_up_to_date(project_dir, &archive_path)? {
+        return Ok(false);
+    }
+
+    if dry_run {
+        if !quiet {
+            println!(
+                "{} {} {}",
+                "Would archive:".yellow().bold(),
+                project_dir.display(),
+                format!("-> {}", archive_path.display()).dimmed()
+            );
+        }
+        return Ok(true);
+    }
+
+    match format {
+        ArchiveFormat::TarGz => {
+            let file = fs::File::create(&archive_path)?;
+            let enc = GzEncoder::new(file, Compression::default());
+            let mut builder = tar::Builder::new(enc);
+            builder.append_dir_all(&project_name, project_dir)?;
+            builder.finish()?;
+        }
+        ArchiveFormat::Zstd => {
+            let file = fs::File::create(&archive_path)?;
+            let enc = zstd::Encoder::new(file, 0)?.auto_finish();
+            let mut builder = tar::Builder::new(enc);
+            builder.append_dir_all(&project_name, project_dir)?;
+            builder.finish()?;
+        }
+        ArchiveFormat::Zip => {
+            let file = fs::File::create(&archive_path)?;
+            let mut zip = zip::ZipWriter::new(file);
+            let options = zip::write::FileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated);
+            for entry in WalkDir::new(project_dir).into_iter().filter_map(Result::ok) {
+                let path = entry.path();
+                let relative = path.strip_prefix(project_dir)?;
+                if relative.as_os_str().is_empty() {
+                    continue;
+                }
+                let name = relative.to_string_lossy();
+                if entry.file_type().is_dir() {
+                    zip.add_directory(name, options)?;
+                } else {
+                    zip.start_file(name, options)?;
+                    let mut f = fs::File::open(path)?;
+                    io::copy(&mut f, &mut zip)?;
+                }
+            }
+            zip.finish()?;
+        }
+    }
+
+    if !quiet {
+        println!(
+            "{} {} {}",
+            "Archived:".green().bold(),
+            project_dir.display(),
+            format!("-> {}", archive_path.display()).dimmed()
+        );
+    }
+
+    Ok(true)
+}
+
+/// Check whether `project_dir` has been untouched for at least `min_age_days`, looking
+/// only at its source files (i.e. excluding `target_dirs`). Symlinks are not followed,
+/// and a project with no files at all is treated as stale.
+fn is_project_stale(project_dir: &Path, target_dirs: &[&str], min_age_days: u64) -> bool {
+    let newest_mtime = WalkDir::new(project_dir)
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0 || !target_dirs.iter().any(|t| *t == e.file_name().to_string_lossy())
+        })
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .filter_map(|m| m.modified().ok())
+        .max();
+
+    let Some(mtime) = newest_mtime else {
+        return true;
+    };
+
+    let age = SystemTime::now().duration_since(mtime).unwrap_or_default();
+    age >= Duration::from_secs(min_age_days * 24 * 60 * 60)
+}
+
+/// Check whether `archive_path` already reflects the current contents of `project_dir`.
+fn is_archive_up_to_date(project_dir: &Path, archive_path: &Path) -> Result<bool> {
+    if !archive_path.exists() {
+        return Ok(false);
+    }
+
+    let archive_mtime = fs::metadata(archive_path)?.modified()?;
+    let newest_source_mtime = WalkDir::new(project_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .filter_map(|m| m.modified().ok())
+        .max();
+
+    Ok(newest_source_mtime.is_none_or(|newest| archive_mtime >= newest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(project_dir: &str, target_path: &str, language: &str) -> Candidate {
+        Candidate {
+            project_dir: PathBuf::from(project_dir),
+            target_path: PathBuf::from(target_path),
+            language: language.to_string(),
+            size_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn format_bytes_rounds_to_the_largest_sensible_unit() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.0 KiB");
+        assert_eq!(format_bytes(1024 * 1024 * 3 / 2), "1.5 MiB");
+    }
+
+    #[test]
+    fn summarize_tallies_cleaned_skipped_and_freed_bytes() {
+        let results = vec![
+            CandidateResult {
+                path: PathBuf::from("a/target"),
+                language: "Rust".to_string(),
+                size_bytes: 100,
+                action: ActionKind::Removed,
+                error: None,
+            },
+            CandidateResult {
+                path: PathBuf::from("b/target"),
+                language: "Rust".to_string(),
+                size_bytes: 200,
+                action: ActionKind::WouldRemove,
+                error: None,
+            },
+            CandidateResult {
+                path: PathBuf::from("c/target"),
+                language: "Rust".to_string(),
+                size_bytes: 50,
+                action: ActionKind::Failed,
+                error: Some("permission denied".to_string()),
+            },
+        ];
+
+        let (cleaned, skipped, freed_bytes) = summarize(&results);
+        assert_eq!(cleaned, 2);
+        assert_eq!(skipped, 1);
+        assert_eq!(freed_bytes, 300);
+    }
+
+    #[test]
+    fn dedup_candidates_keeps_first_of_exact_duplicates() {
+        let candidates = vec![
+            candidate("proj", "proj/target", "Rust"),
+            candidate("proj", "proj/target", "Rust"),
+        ];
+
+        let deduped = dedup_candidates(candidates);
+        assert_eq!(deduped.len(), 1);
+    }
+
+    #[test]
+    fn dedup_candidates_drops_nested_target_paths() {
+        // A node_modules dependency can carry its own package.json, so the
+        // nested node_modules becomes a second, overlapping candidate.
+        let candidates = vec![
+            candidate("proj", "proj/node_modules", "JavaScript/TypeScript"),
+            candidate(
+                "proj/node_modules/dep",
+                "proj/node_modules/dep/node_modules",
+                "JavaScript/TypeScript",
+            ),
+        ];
+
+        let deduped = dedup_candidates(candidates);
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].target_path, PathBuf::from("proj/node_modules"));
+    }
+
+    #[test]
+    fn dedup_candidates_keeps_unrelated_target_paths() {
+        let candidates = vec![
+            candidate("proj-a", "proj-a/target", "Rust"),
+            candidate("proj-b", "proj-b/target", "Rust"),
+        ];
+
+        let deduped = dedup_candidates(candidates);
+        assert_eq!(deduped.len(), 2);
+    }
+
+    #[test]
+    fn language_matcher_matches_on_exact_indicator_file() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+
+        let matcher = LanguageMatcher {
+            language: "Rust".to_string(),
+            files: vec!["Cargo.toml".to_string()],
+            extensions: vec![],
+            folders: vec!["target".to_string()],
+        };
+
+        assert!(matcher.matches(dir.path()));
+    }
+
+    #[test]
+    fn language_matcher_matches_on_extension_suffix_not_bare_substring() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("mycsproj"), "").unwrap();
+
+        let matcher = LanguageMatcher {
+            language: ".NET".to_string(),
+            files: vec![],
+            extensions: vec![".csproj".to_string()],
+            folders: vec!["bin".to_string()],
+        };
+
+        // "mycsproj" contains "csproj" but doesn't end with ".csproj".
+        assert!(!matcher.matches(dir.path()));
+
+        fs::write(dir.path().join("App.csproj"), "").unwrap();
+        assert!(matcher.matches(dir.path()));
+    }
+
+    #[test]
+    fn language_matcher_from_profile_splits_wildcard_and_exact_indicators() {
+        let profile = config::Profile {
+            name: "Zig".to_string(),
+            indicators: vec!["build.zig".to_string(), "*.zig-project".to_string()],
+            targets: vec!["zig-cache".to_string()],
+        };
+
+        let matcher = LanguageMatcher::from_profile(&profile);
+        assert_eq!(matcher.language, "Zig");
+        assert_eq!(matcher.files, vec!["build.zig".to_string()]);
+        assert_eq!(matcher.extensions, vec![".zig-project".to_string()]);
+        assert_eq!(matcher.folders, vec!["zig-cache".to_string()]);
+    }
+
+    #[test]
+    fn is_project_stale_treats_empty_project_as_stale() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(is_project_stale(dir.path(), &["target"], 0));
+    }
+
+    #[test]
+    fn is_project_stale_ignores_files_inside_target_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("target")).unwrap();
+        fs::write(dir.path().join("target").join("build.log"), "fresh").unwrap();
+
+        // Only the (excluded) target dir has content, so the project itself
+        // still looks untouched and counts as stale.
+        assert!(is_project_stale(dir.path(), &["target"], 9999));
+    }
+
+    #[test]
+    fn is_project_stale_is_false_for_a_freshly_written_file() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("main.rs"), "fn main() {}").unwrap();
+
+        assert!(!is_project_stale(dir.path(), &["target"], 9999));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn is_project_stale_does_not_follow_symlinks() {
+        let dir = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        fs::write(outside.path().join("fresh.txt"), "fresh").unwrap();
+        std::os::unix::fs::symlink(outside.path().join("fresh.txt"), dir.path().join("link.txt"))
+            .unwrap();
+
+        // The only entry is a symlink to a freshly-modified file elsewhere; since
+        // symlinks aren't followed, the project has no real files and is stale.
+        assert!(is_project_stale(dir.path(), &["target"], 9999));
+    }
 }